@@ -0,0 +1,22 @@
+// @ts-check
+const { defineConfig, devices } = require("@playwright/test");
+
+/** Serves the example app with `dx serve` and runs the router tests against it. */
+module.exports = defineConfig({
+  testDir: "./tests",
+  // Transitions settle well under this, it only bounds a stuck outlet
+  expect: { timeout: 5_000 },
+  use: {
+    baseURL: "http://127.0.0.1:8080",
+    trace: "retain-on-failure",
+  },
+  projects: [{ name: "chromium", use: { ...devices["Desktop Chrome"] } }],
+  webServer: {
+    command: "dx serve --port 8080",
+    cwd: "..",
+    url: "http://127.0.0.1:8080",
+    reuseExistingServer: !process.env.CI,
+    // The first run compiles the wasm bundle
+    timeout: 600_000,
+  },
+});
@@ -0,0 +1,102 @@
+// @ts-check
+const { test, expect } = require("@playwright/test");
+
+const heading = (page, name) => page.getByRole("heading", { name, exact: true });
+
+// While a transition runs the outgoing layer still holds the previous page, so
+// the new heading alone proves nothing. Waiting for the previous heading to go
+// away as well is what shows the outlet settled.
+const settledOn = async (page, next, previous) => {
+  await expect(heading(page, next)).toHaveCount(1);
+  await expect(heading(page, next)).toBeVisible();
+  if (previous) {
+    await expect(heading(page, previous)).toHaveCount(0);
+  }
+};
+
+// True once neither the element nor any ancestor is still offset, scaled or rotated.
+const untransformed = (locator) =>
+  locator.evaluate((element) => {
+    for (let node = element; node; node = node.parentElement) {
+      const { transform } = getComputedStyle(node);
+      if (transform === "none") continue;
+      const { a, b, c, d, e, f } = new DOMMatrixReadOnly(transform);
+      const near = (value, target) => Math.abs(value - target) < 0.01;
+      if (!(near(a, 1) && near(b, 0) && near(c, 0) && near(d, 1) && near(e, 0) && near(f, 0))) {
+        return false;
+      }
+    }
+    return true;
+  });
+
+test("home renders once", async ({ page }) => {
+  await page.goto("/");
+  await settledOn(page, "Welcome to the Dioxus Blog!");
+});
+
+test("navigates into the blog and a post", async ({ page }) => {
+  await page.goto("/");
+  await page.getByRole("link", { name: "Blog", exact: true }).click();
+
+  await settledOn(page, "Choose a post", "Welcome to the Dioxus Blog!");
+  await expect(heading(page, "Blog")).toHaveCount(1);
+
+  await page.getByRole("link", { name: "Read the first blog post" }).click();
+  await settledOn(page, "Blog post 1", "Choose a post");
+  await expect(heading(page, "Blog")).toHaveCount(1);
+});
+
+test("back navigation settles on the previous page", async ({ page }) => {
+  await page.goto("/blog");
+  await page.getByRole("link", { name: "Read the second blog post" }).click();
+  await settledOn(page, "Blog post 2", "Choose a post");
+
+  await page.goBack();
+  await settledOn(page, "Choose a post", "Blog post 2");
+});
+
+test("myblog redirects to the blog", async ({ page }) => {
+  await page.goto("/myblog");
+  await settledOn(page, "Choose a post");
+  await expect(page).toHaveURL(/\/blog\/?$/);
+  await expect(heading(page, "Blog")).toHaveCount(1);
+});
+
+test("unknown routes render the not found page", async ({ page }) => {
+  await page.goto("/does/not/exist");
+  await settledOn(page, "Page not found");
+  await expect(page.getByText('["does", "not", "exist"]')).toBeVisible();
+});
+
+test("navigation animates both layers before settling", async ({ page }) => {
+  await page.goto("/gallery");
+  await settledOn(page, "Pick a transition");
+
+  await page.getByRole("link", { name: "SlideLeft", exact: true }).click();
+
+  // An outlet that swapped pages instantly would never show both at once
+  await expect(heading(page, "SlideLeft")).toHaveCount(1);
+  await expect(heading(page, "Pick a transition")).toHaveCount(1);
+  expect(await untransformed(heading(page, "SlideLeft"))).toBe(false);
+
+  await expect.poll(() => untransformed(heading(page, "SlideLeft"))).toBe(true);
+  await settledOn(page, "SlideLeft", "Pick a transition");
+});
+
+test("every gallery transition settles on its demo page", async ({ page }) => {
+  await page.goto("/gallery");
+  await settledOn(page, "Pick a transition");
+
+  const variants = await page.locator("#gallery-list a").allInnerTexts();
+  expect(variants).toEqual(["SlideLeft", "SlideRight", "SlideUp", "SlideDown", "Fade", "ZoomIn"]);
+
+  for (const variant of variants) {
+    await page.getByRole("link", { name: variant, exact: true }).click();
+    await settledOn(page, variant, "Pick a transition");
+    await expect(heading(page, "Transition gallery")).toHaveCount(1);
+
+    await page.getByRole("link", { name: "Back to the gallery" }).click();
+    await settledOn(page, "Pick a transition", variant);
+    await expect(heading(page, "Transition gallery")).toHaveCount(1);
+  }
+});
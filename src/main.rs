@@ -51,6 +51,39 @@ enum Route {
         #[end_layout]
         #[end_nest]
 
+        // A gallery with one demo page per transition variant, built the same way as the blog
+        #[nest("/gallery")]
+        #[layout(Gallery)]
+            #[route("/")]
+            #[transition(Fade)]
+            GalleryIndex {},
+
+            #[route("/slide-left")]
+            #[transition(SlideLeft)]
+            SlideLeftDemo {},
+
+            #[route("/slide-right")]
+            #[transition(SlideRight)]
+            SlideRightDemo {},
+
+            #[route("/slide-up")]
+            #[transition(SlideUp)]
+            SlideUpDemo {},
+
+            #[route("/slide-down")]
+            #[transition(SlideDown)]
+            SlideDownDemo {},
+
+            #[route("/fade")]
+            #[transition(Fade)]
+            FadeDemo {},
+
+            #[route("/zoom-in")]
+            #[transition(ZoomIn)]
+            ZoomInDemo {},
+        #[end_layout]
+        #[end_nest]
+
     // And the regular page layout
     #[end_layout]
 
@@ -73,6 +106,7 @@ fn NavBar() -> Element {
         nav { id: "navbar take it",
             Link { to: Route::Home {}, "Home" }
             Link { to: Route::BlogList {}, "Blog" }
+            Link { to: Route::GalleryIndex {}, "Gallery" }
         }
         AnimatedOutlet::<Route> {}
     }
@@ -130,6 +164,87 @@ fn BlogPost(name: String) -> Element {
     }
 }
 
+// The variants showcased in the gallery, paired with the route that demos each one.
+// Each label has to match the `#[transition(...)]` declared on its route; nothing checks that.
+fn gallery_entries() -> Vec<(&'static str, Route)> {
+    vec![
+        ("SlideLeft", Route::SlideLeftDemo {}),
+        ("SlideRight", Route::SlideRightDemo {}),
+        ("SlideUp", Route::SlideUpDemo {}),
+        ("SlideDown", Route::SlideDownDemo {}),
+        ("Fade", Route::FadeDemo {}),
+        ("ZoomIn", Route::ZoomInDemo {}),
+    ]
+}
+
+#[component]
+fn Gallery() -> Element {
+    rsx! {
+        h1 { "Transition gallery" }
+        AnimatedOutlet::<Route> {}
+    }
+}
+
+#[component]
+fn GalleryIndex() -> Element {
+    rsx! {
+        h2 { "Pick a transition" }
+        div { id: "gallery-list",
+            for (variant, route) in gallery_entries() {
+                Link { key: "{variant}", to: route, "{variant}" }
+            }
+        }
+    }
+}
+
+// The demo pages only differ by the transition declared on their route.
+// Each one passes its own route rather than reading `use_route`, because the
+// outgoing layer of a transition sees the incoming route there.
+#[component]
+fn GalleryDemo(route: Route) -> Element {
+    let variant = gallery_entries()
+        .into_iter()
+        .find(|(_, entry)| *entry == route)
+        .map(|(variant, _)| variant)
+        .unwrap_or_else(|| panic!("{route:?} is missing from gallery_entries()"));
+
+    rsx! {
+        h2 { "{variant}" }
+        p { "This page entered with the {variant} transition." }
+        Link { to: Route::GalleryIndex {}, "Back to the gallery" }
+    }
+}
+
+#[component]
+fn SlideLeftDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::SlideLeftDemo {} } }
+}
+
+#[component]
+fn SlideRightDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::SlideRightDemo {} } }
+}
+
+#[component]
+fn SlideUpDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::SlideUpDemo {} } }
+}
+
+#[component]
+fn SlideDownDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::SlideDownDemo {} } }
+}
+
+#[component]
+fn FadeDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::FadeDemo {} } }
+}
+
+#[component]
+fn ZoomInDemo() -> Element {
+    rsx! { GalleryDemo { route: Route::ZoomInDemo {} } }
+}
+
 #[component]
 fn PageNotFound(route: Vec<String>) -> Element {
     rsx! {